@@ -0,0 +1,21 @@
+use std::io;
+
+/// Trait for encoding values into a byte buffer for use by `FramedWrite`.
+///
+/// The corresponding `decode` half lives with whatever reads the bytes back
+/// off the wire; this trait only concerns itself with the write side.
+pub trait Encoder {
+    /// The type of items that will be encoded.
+    type Item;
+
+    /// The type of encoding errors.
+    ///
+    /// Required to implement `From<io::Error>` so that I/O errors that
+    /// occur while writing the encoded bytes can be coerced into this
+    /// type via the `?` operator.
+    type Error: From<io::Error>;
+
+    /// Encodes an item into the buffer provided, appending to what is
+    /// already there.
+    fn encode(&mut self, item: Self::Item, dst: &mut Vec<u8>) -> Result<(), Self::Error>;
+}
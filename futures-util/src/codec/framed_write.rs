@@ -0,0 +1,132 @@
+use core::pin::Pin;
+use std::io;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncWrite;
+use futures_sink::Sink;
+
+use super::Encoder;
+
+/// Once the outbound buffer holds this many bytes or more, `poll_ready`
+/// stops accepting new items until the writer has written all of it out.
+const HIGH_WATER_MARK: usize = 8 * 1024;
+
+/// A `Sink` of frames encoded to bytes, written to an `AsyncWrite`.
+///
+/// This adapter turns any byte-oriented `AsyncWrite` into a typed `Sink`
+/// by running each outgoing item through an `Encoder` before appending the
+/// resulting bytes to an internal buffer, which is then written out to the
+/// underlying I/O object.
+#[derive(Debug)]
+#[must_use = "sinks do nothing unless polled"]
+pub struct FramedWrite<T, E> {
+    writer: T,
+    encoder: E,
+    buffer: Vec<u8>,
+    written: usize,
+}
+
+impl<T: Unpin, E> Unpin for FramedWrite<T, E> {}
+
+impl<T, E> FramedWrite<T, E>
+    where T: AsyncWrite,
+{
+    /// Creates a new `FramedWrite` from an inner I/O object and an encoder.
+    pub fn new(writer: T, encoder: E) -> Self {
+        FramedWrite {
+            writer,
+            encoder,
+            buffer: Vec::new(),
+            written: 0,
+        }
+    }
+
+    /// Returns a reference to the underlying I/O object.
+    pub fn get_ref(&self) -> &T {
+        &self.writer
+    }
+
+    /// Returns a mutable reference to the underlying I/O object.
+    ///
+    /// Note that care should be taken to avoid writing directly to the
+    /// underlying object, as that could corrupt the framing.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.writer
+    }
+
+    /// Consumes the `FramedWrite`, returning the underlying I/O object.
+    ///
+    /// Note that any leftover buffered bytes are discarded.
+    pub fn into_inner(self) -> T {
+        self.writer
+    }
+}
+
+impl<T, E> FramedWrite<T, E>
+    where T: AsyncWrite + Unpin,
+          E: Encoder,
+{
+    /// Drives the outbound buffer towards empty, writing through to the
+    /// underlying I/O object. Tracks the write cursor across polls so a
+    /// partially written buffer is never dropped. A short write is not an
+    /// error: the cursor advances and the rest is written on the next pass,
+    /// and a writer that is not ready surfaces as pending. Only a write of
+    /// zero bytes is reported as a `WriteZero` error.
+    fn poll_drain_buffer(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), E::Error>> {
+        while self.written < self.buffer.len() {
+            match Pin::new(&mut self.writer).poll_write(cx, &self.buffer[self.written..]) {
+                Poll::Ready(Ok(0)) => {
+                    let err = io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write frame to transport",
+                    );
+                    return Poll::Ready(Err(err.into()));
+                }
+                Poll::Ready(Ok(n)) => self.written += n,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e.into())),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        self.buffer.clear();
+        self.written = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T, E> Sink for FramedWrite<T, E>
+    where T: AsyncWrite + Unpin,
+          E: Encoder,
+{
+    type SinkItem = E::Item;
+    type SinkError = E::Error;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        if self.buffer.len() >= HIGH_WATER_MARK {
+            match self.poll_drain_buffer(cx) {
+                Poll::Ready(Ok(())) => {}
+                other => return other,
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Self::SinkItem) -> Result<(), Self::SinkError> {
+        let this = self.get_mut();
+        this.encoder.encode(item, &mut this.buffer)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        match self.poll_drain_buffer(cx) {
+            Poll::Ready(Ok(())) => {}
+            other => return other,
+        }
+        Pin::new(&mut self.writer).poll_flush(cx).map_err(Into::into)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        match self.poll_drain_buffer(cx) {
+            Poll::Ready(Ok(())) => {}
+            other => return other,
+        }
+        Pin::new(&mut self.writer).poll_close(cx).map_err(Into::into)
+    }
+}
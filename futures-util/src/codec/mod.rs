@@ -0,0 +1,15 @@
+//! Utilities for encoding frames using `async`/`await`.
+//!
+//! Contains an adapter to go from a byte-oriented `AsyncWrite` to a framed
+//! sink implementing `Sink`, driven by an `Encoder`. Only the write side is
+//! provided: there is no decoder, and no adapter from `AsyncRead` to a
+//! framed `Stream`.
+//!
+//! This module is only available when the `std` feature of this library
+//! is activated, and it is activated by default.
+
+mod encoder;
+pub use self::encoder::Encoder;
+
+mod framed_write;
+pub use self::framed_write::FramedWrite;
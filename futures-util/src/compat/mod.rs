@@ -0,0 +1,7 @@
+//! Interop between `futures` 0.1 and 0.3.
+//!
+//! This module is only available when the `compat` feature of this
+//! library is activated.
+
+mod sink;
+pub use self::sink::{CompatSink, Sink01CompatExt};
@@ -0,0 +1,157 @@
+use core::pin::Pin;
+use std::sync::Arc;
+use futures_01::Async as Async01;
+use futures_01::AsyncSink as AsyncSink01;
+use futures_01::Sink as Sink01;
+use futures_01::executor::{self as executor01, Notify as Notify01};
+use futures_core::task::{Context, Poll, Waker};
+use futures_sink::Sink as Sink03;
+
+/// Extension trait for `futures` 0.1 `Sink`s, adding a `compat` method that
+/// bridges into a 0.3 `Sink`, so that all of `SinkExt`'s combinators can be
+/// used on legacy sinks.
+pub trait Sink01CompatExt: Sink01 {
+    /// Wraps a `futures` 0.1 `Sink` to produce a `futures` 0.3 `Sink`.
+    fn compat(self) -> CompatSink<Self>
+        where Self: Sized,
+    {
+        CompatSink::new(self)
+    }
+}
+
+impl<S: Sink01> Sink01CompatExt for S {}
+
+/// A 0.1 `Notify` that forwards wakeups to a 0.3 `Waker`.
+///
+/// This is the other half of the bridge: a 0.1 sink that can't make
+/// progress calls `futures_01::task::current()` and stashes the returned
+/// handle, to be notified later. Installing one of these as the "current"
+/// 0.1 task for the duration of the inner call means that stashed handle
+/// is really just our `Waker` in disguise, so the 0.3 executor still gets
+/// woken.
+struct WakerToHandle(Waker);
+
+impl Notify01 for WakerToHandle {
+    fn notify(&self, _id: usize) {
+        self.0.wake_by_ref();
+    }
+}
+
+/// Runs `f` with a 0.1 task context installed whose notifications are
+/// forwarded to `cx`'s waker, so that a 0.1 `Sink`'s internal
+/// `task::current()` calls register for wakeups exactly as they would
+/// inside a real 0.1 task.
+fn with_01_task_cx<F, R>(cx: &mut Context<'_>, f: F) -> R
+    where F: FnOnce() -> R,
+{
+    let notify = Arc::new(WakerToHandle(cx.waker().clone()));
+    executor01::with_notify(&notify, 0, f)
+}
+
+/// Converts a `futures` 0.1 `Sink` into a `futures` 0.3 `Sink`.
+///
+/// 0.1 sinks signal backpressure by handing a rejected item back from
+/// `start_send`, while 0.3 sinks split that into a separate `poll_ready`
+/// check. `CompatSink` bridges the two by buffering one item: `start_send`
+/// stores it, the next `poll_ready`, `poll_flush` or `poll_close` hands it
+/// to the 0.1 sink, and if the 0.1 sink declines it, it stays buffered and
+/// is retried the next time the adapter is polled. Every call into the inner sink runs inside a 0.1 task context
+/// derived from the 0.3 `Waker`, so backpressure registered the 0.1 way
+/// (`task::current()`) still wakes the surrounding 0.3 task.
+#[derive(Debug)]
+#[must_use = "sinks do nothing unless polled"]
+pub struct CompatSink<S: Sink01> {
+    inner: S,
+    buffered: Option<S::SinkItem>,
+}
+
+impl<S: Sink01> Unpin for CompatSink<S> {}
+
+impl<S: Sink01> CompatSink<S> {
+    pub(crate) fn new(inner: S) -> Self {
+        CompatSink { inner, buffered: None }
+    }
+
+    /// Get a reference to the inner sink.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner sink.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+
+    /// Consume this adapter, returning the inner sink.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn try_empty_buffer(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), S::SinkError>> {
+        match self.buffered.take() {
+            Some(item) => {
+                let inner = &mut self.inner;
+                match with_01_task_cx(cx, || inner.start_send(item)) {
+                    Ok(AsyncSink01::Ready) => Poll::Ready(Ok(())),
+                    Ok(AsyncSink01::NotReady(item)) => {
+                        self.buffered = Some(item);
+                        Poll::Pending
+                    }
+                    Err(e) => Poll::Ready(Err(e)),
+                }
+            }
+            None => Poll::Ready(Ok(())),
+        }
+    }
+}
+
+impl<S: Sink01> Sink03 for CompatSink<S> {
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        self.get_mut().try_empty_buffer(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Self::SinkItem) -> Result<(), Self::SinkError> {
+        // `start_send` has no `Context` to install a 0.1 task with, and the
+        // 0.3 contract guarantees `poll_ready` returned `Ready` (and thus
+        // drained `buffered`) just before this call. So rather than call
+        // into the inner sink here with no way to bridge its wakeups,
+        // just buffer the item; `poll_ready`/`poll_flush`/`poll_close`
+        // (which do have a `cx`) are the only places that ever touch
+        // `inner` directly.
+        let this = self.get_mut();
+        debug_assert!(this.buffered.is_none());
+        this.buffered = Some(item);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        let this = self.get_mut();
+        match this.try_empty_buffer(cx) {
+            Poll::Ready(Ok(())) => {}
+            other => return other,
+        }
+        let inner = &mut this.inner;
+        match with_01_task_cx(cx, || inner.poll_complete()) {
+            Ok(Async01::Ready(())) => Poll::Ready(Ok(())),
+            Ok(Async01::NotReady) => Poll::Pending,
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        let this = self.get_mut();
+        match this.try_empty_buffer(cx) {
+            Poll::Ready(Ok(())) => {}
+            other => return other,
+        }
+        let inner = &mut this.inner;
+        match with_01_task_cx(cx, || inner.close()) {
+            Ok(Async01::Ready(())) => Poll::Ready(Ok(())),
+            Ok(Async01::NotReady) => Poll::Pending,
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use core::marker::PhantomData;
+use core::pin::Pin;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+
+/// A sink that will discard all items given to it.
+///
+/// This sink is created by the `drain` function.
+#[derive(Debug)]
+#[must_use = "sinks do nothing unless polled"]
+pub struct Drain<T> {
+    marker: PhantomData<T>,
+}
+
+/// Create a sink that will discard all items given to it.
+///
+/// Useful for benchmarking or sinking one branch of a `fanout` in tests,
+/// where only the stream side of the pipeline matters.
+pub fn drain<T>() -> Drain<T> {
+    Drain { marker: PhantomData }
+}
+
+/// The error type for the `Drain` sink.
+///
+/// This error is uninhabited and can never actually be produced; `Drain`'s
+/// methods always return `Ok`. Being uninhabited doesn't give it a free
+/// `Into<E>` for an arbitrary `E` (that would overlap with the reflexive
+/// `From` impl), so composing a `Drain` into a pipeline with a different
+/// error type still needs an explicit `sink_map_err` that matches on the
+/// (unreachable) variants of this enum, rather than a plain `sink_err_into`.
+///
+/// Known limitation: automatic `sink_err_into` composability was the
+/// original ask for this type, but Rust's coherence rules make a blanket
+/// conversion out of an uninhabited enum impossible to provide here, so
+/// that part of the request is not delivered as asked.
+#[derive(Debug)]
+pub enum DrainError {}
+
+impl<T> Sink for Drain<T> {
+    type SinkItem = T;
+    type SinkError = DrainError;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, _item: Self::SinkItem) -> Result<(), Self::SinkError> {
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+}
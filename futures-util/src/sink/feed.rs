@@ -0,0 +1,40 @@
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+
+/// Future for the `feed` combinator, which pushes a value into a sink and
+/// completes as soon as the sink has accepted it, without flushing.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct Feed<'a, Si: Sink + Unpin + ?Sized> {
+    sink: &'a mut Si,
+    item: Option<Si::SinkItem>,
+}
+
+impl<Si: Sink + Unpin + ?Sized> Unpin for Feed<'_, Si> {}
+
+impl<Si: Sink + Unpin + ?Sized> Feed<'_, Si> {
+    pub(super) fn new(sink: &mut Si, item: Si::SinkItem) -> Feed<Si> {
+        Feed {
+            sink,
+            item: Some(item),
+        }
+    }
+}
+
+impl<Si: Sink + Unpin + ?Sized> Future for Feed<'_, Si> {
+    type Output = Result<(), Si::SinkError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let item = self.item.take().expect("polled Feed after completion");
+        match Pin::new(&mut self.sink).poll_ready(cx) {
+            Poll::Ready(Ok(())) => Poll::Ready(Pin::new(&mut self.sink).start_send(item)),
+            Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+            Poll::Pending => {
+                self.item = Some(item);
+                Poll::Pending
+            }
+        }
+    }
+}
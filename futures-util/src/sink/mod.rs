@@ -12,6 +12,9 @@ use futures_sink::Sink;
 mod close;
 pub use self::close::Close;
 
+mod drain;
+pub use self::drain::{drain, Drain, DrainError};
+
 mod fanout;
 pub use self::fanout::Fanout;
 
@@ -21,6 +24,9 @@ pub use self::flush::Flush;
 mod err_into;
 pub use self::err_into::SinkErrInto;
 
+mod feed;
+pub use self::feed::Feed;
+
 mod map_err;
 pub use self::map_err::SinkMapErr;
 
@@ -36,6 +42,18 @@ pub use self::with::With;
 mod with_flat_map;
 pub use self::with_flat_map::WithFlatMap;
 
+mod with_map;
+pub use self::with_map::WithMap;
+
+mod with_filter;
+pub use self::with_filter::WithFilter;
+
+mod with_filter_map;
+pub use self::with_filter_map::WithFilterMap;
+
+mod unfold;
+pub use self::unfold::{unfold, Unfold};
+
 if_std! {
     mod buffer;
     pub use self::buffer::Buffer;
@@ -108,19 +126,46 @@ pub trait SinkExt: Sink {
         WithFlatMap::new(self, f)
     }
 
-    /*
+    /// Composes a function *in front of* the sink.
+    ///
+    /// This adapter produces a new sink that passes each value through the
+    /// given function `f` before sending it to `self`.
+    ///
+    /// Unlike `with`, `f` is a plain synchronous function rather than one
+    /// producing a future, so no future needs to be polled to completion
+    /// before the item can be forwarded. This makes `with_map` much cheaper
+    /// than `with` when no asynchronous work is needed per item.
     fn with_map<U, F>(self, f: F) -> WithMap<Self, U, F>
         where F: FnMut(U) -> Self::SinkItem,
-              Self: Sized;
+              Self: Sized,
+    {
+        WithMap::new(self, f)
+    }
 
+    /// Composes a filter *in front of* the sink.
+    ///
+    /// This adapter produces a new sink that only forwards values for which
+    /// `f` returns `true`. Values for which `f` returns `false` are dropped
+    /// and the sink reports readiness without forwarding anything.
     fn with_filter<F>(self, f: F) -> WithFilter<Self, F>
-        where F: FnMut(Self::SinkItem) -> bool,
-              Self: Sized;
+        where F: FnMut(&Self::SinkItem) -> bool,
+              Self: Sized,
+    {
+        WithFilter::new(self, f)
+    }
 
+    /// Composes a function *in front of* the sink that both filters and
+    /// maps in a single synchronous pass.
+    ///
+    /// This adapter produces a new sink that passes each value through the
+    /// given function `f` before sending it to `self`. Values for which `f`
+    /// returns `None` are dropped instead of being forwarded.
     fn with_filter_map<U, F>(self, f: F) -> WithFilterMap<Self, U, F>
         where F: FnMut(U) -> Option<Self::SinkItem>,
-              Self: Sized;
-     */
+              Self: Sized,
+    {
+        WithFilterMap::new(self, f)
+    }
 
     /// Transforms the error returned by the sink.
     fn sink_map_err<E, F>(self, f: F) -> SinkMapErr<Self, F>
@@ -201,6 +246,18 @@ pub trait SinkExt: Sink {
         Send::new(self, item)
     }
 
+    /// A future that completes after the given item has been received by
+    /// the sink.
+    ///
+    /// Unlike `send`, the returned future does not flush the sink. It is
+    /// provided in case you want to buffer more than one item at a time to
+    /// send, rather than flushing between each item.
+    fn feed<'a>(&'a mut self, item: Self::SinkItem) -> Feed<'a, Self>
+        where Self: Unpin,
+    {
+        Feed::new(self, item)
+    }
+
     /// A future that completes after the given stream has been fully processed
     /// into the sink, including flushing.
     ///
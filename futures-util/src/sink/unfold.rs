@@ -0,0 +1,117 @@
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Create a sink from a function which processes one item at a time.
+///
+/// `init` is the initial state, and each incoming item is combined with
+/// the current state via `f` to produce a future that resolves to the next
+/// state (or an error). This makes it possible to build an ad-hoc stateful
+/// `Sink` (an accumulator, a writer that does per-item async work, ...)
+/// without hand-writing a type that implements the trait.
+pub fn unfold<T, F, R, Item, E>(init: T, function: F) -> Unfold<T, F, R>
+    where F: FnMut(T, Item) -> R,
+          R: Future<Output = Result<T, E>>,
+{
+    Unfold {
+        function,
+        state: UnfoldState::Value { value: init },
+    }
+}
+
+/// Sink for the `unfold` function.
+#[derive(Debug)]
+#[must_use = "sinks do nothing unless polled"]
+pub struct Unfold<T, F, R> {
+    function: F,
+    state: UnfoldState<T, R>,
+}
+
+impl<T, F, R> Unfold<T, F, R> {
+    unsafe_unpinned!(function: F);
+    unsafe_pinned!(state: UnfoldState<T, R>);
+}
+
+#[derive(Debug)]
+enum UnfoldState<T, R> {
+    Value { value: T },
+    Future { future: R },
+    Empty,
+}
+
+impl<T, R> UnfoldState<T, R> {
+    fn project_future(self: Pin<&mut Self>) -> Option<Pin<&mut R>> {
+        unsafe {
+            match Pin::get_unchecked_mut(self) {
+                UnfoldState::Future { future } => Some(Pin::new_unchecked(future)),
+                _ => None,
+            }
+        }
+    }
+
+    fn take_value(self: Pin<&mut Self>) -> Option<T> {
+        unsafe {
+            let this = Pin::get_unchecked_mut(self);
+            match this {
+                UnfoldState::Value { .. } => {
+                    match core::mem::replace(this, UnfoldState::Empty) {
+                        UnfoldState::Value { value } => Some(value),
+                        _ => unreachable!(),
+                    }
+                }
+                _ => None,
+            }
+        }
+    }
+}
+
+impl<T, F, R, Item, E> Sink for Unfold<T, F, R>
+    where F: FnMut(T, Item) -> R,
+          R: Future<Output = Result<T, E>>,
+{
+    type SinkItem = Item;
+    type SinkError = E;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        self.poll_flush(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: Self::SinkItem) -> Result<(), Self::SinkError> {
+        let value = self.as_mut().state().take_value()
+            .expect("start_send called without poll_ready being called first");
+        let future = (self.as_mut().function())(value, item);
+        self.as_mut().state().set(UnfoldState::Future { future });
+        Ok(())
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        match self.as_mut().state().project_future() {
+            Some(future) => match future.poll(cx) {
+                Poll::Ready(result) => {
+                    // Transition out of `Future` before propagating an
+                    // error, so a completed future is never left behind
+                    // for the next `poll_flush`/`poll_close` to re-poll.
+                    let result = result.map(|value| UnfoldState::Value { value });
+                    match result {
+                        Ok(state) => {
+                            self.as_mut().state().set(state);
+                            Poll::Ready(Ok(()))
+                        }
+                        Err(e) => {
+                            self.as_mut().state().set(UnfoldState::Empty);
+                            Poll::Ready(Err(e))
+                        }
+                    }
+                }
+                Poll::Pending => Poll::Pending,
+            },
+            None => Poll::Ready(Ok(())),
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        self.poll_flush(cx)
+    }
+}
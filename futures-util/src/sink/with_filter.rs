@@ -0,0 +1,50 @@
+use core::pin::Pin;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Sink for the `with_filter` combinator, which drops items that fail a
+/// predicate before they reach the underlying sink.
+#[derive(Debug)]
+#[must_use = "sinks do nothing unless polled"]
+pub struct WithFilter<Si, F> {
+    sink: Si,
+    f: F,
+}
+
+impl<Si, F> WithFilter<Si, F> {
+    unsafe_pinned!(sink: Si);
+    unsafe_unpinned!(f: F);
+
+    pub(super) fn new(sink: Si, f: F) -> Self {
+        WithFilter { sink, f }
+    }
+}
+
+impl<Si, F> Sink for WithFilter<Si, F>
+    where Si: Sink,
+          F: FnMut(&Si::SinkItem) -> bool,
+{
+    type SinkItem = Si::SinkItem;
+    type SinkError = Si::SinkError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        self.sink().poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: Self::SinkItem) -> Result<(), Self::SinkError> {
+        if (self.as_mut().f())(&item) {
+            self.as_mut().sink().start_send(item)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        self.sink().poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        self.sink().poll_close(cx)
+    }
+}
@@ -0,0 +1,53 @@
+use core::marker::PhantomData;
+use core::pin::Pin;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Sink for the `with_filter_map` combinator, which transforms each item
+/// and drops those for which the function returns `None`, before reaching
+/// the underlying sink.
+#[derive(Debug)]
+#[must_use = "sinks do nothing unless polled"]
+pub struct WithFilterMap<Si, U, F> {
+    sink: Si,
+    f: F,
+    _phantom: PhantomData<fn(U)>,
+}
+
+impl<Si, U, F> WithFilterMap<Si, U, F> {
+    unsafe_pinned!(sink: Si);
+    unsafe_unpinned!(f: F);
+
+    pub(super) fn new(sink: Si, f: F) -> Self {
+        WithFilterMap { sink, f, _phantom: PhantomData }
+    }
+}
+
+impl<Si, U, F> Sink for WithFilterMap<Si, U, F>
+    where Si: Sink,
+          F: FnMut(U) -> Option<Si::SinkItem>,
+{
+    type SinkItem = U;
+    type SinkError = Si::SinkError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        self.sink().poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: Self::SinkItem) -> Result<(), Self::SinkError> {
+        if let Some(item) = (self.as_mut().f())(item) {
+            self.as_mut().sink().start_send(item)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        self.sink().poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        self.sink().poll_close(cx)
+    }
+}
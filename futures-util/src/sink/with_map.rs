@@ -0,0 +1,52 @@
+use core::marker::PhantomData;
+use core::pin::Pin;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Sink for the `with_map` combinator, chaining a plain function to run
+/// *prior* to pushing a value into the underlying sink.
+///
+/// Unlike `with`, the function is synchronous, so no future needs to be
+/// polled to completion before the item can be forwarded.
+#[derive(Debug)]
+#[must_use = "sinks do nothing unless polled"]
+pub struct WithMap<Si, U, F> {
+    sink: Si,
+    f: F,
+    _phantom: PhantomData<fn(U)>,
+}
+
+impl<Si, U, F> WithMap<Si, U, F> {
+    unsafe_pinned!(sink: Si);
+    unsafe_unpinned!(f: F);
+
+    pub(super) fn new(sink: Si, f: F) -> Self {
+        WithMap { sink, f, _phantom: PhantomData }
+    }
+}
+
+impl<Si, U, F> Sink for WithMap<Si, U, F>
+    where Si: Sink,
+          F: FnMut(U) -> Si::SinkItem,
+{
+    type SinkItem = U;
+    type SinkError = Si::SinkError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        self.sink().poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: Self::SinkItem) -> Result<(), Self::SinkError> {
+        let item = (self.as_mut().f())(item);
+        self.as_mut().sink().start_send(item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        self.sink().poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        self.sink().poll_close(cx)
+    }
+}